@@ -21,7 +21,15 @@ fn app() -> Element {
             button {
                 onclick: async move |_| move {
                     if let Some(header) = header_element.read().as_ref().cloned() {
-                        let _ = header.scroll_to(ScrollBehavior::Smooth).await;
+                        // Only scroll if the header has actually been scrolled out of view.
+                        let offscreen = header
+                            .get_client_rect()
+                            .await
+                            .map(|rect| rect.y < 0.0)
+                            .unwrap_or(true);
+                        if offscreen {
+                            let _ = header.scroll_to(ScrollBehavior::Smooth).await;
+                        }
                     }
                 },
                 "Scroll to top"
@@ -0,0 +1,112 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use dioxus_core::ElementId;
+use serde::Deserialize;
+
+use crate::query::{ElementQueryError, QueryEngine};
+
+/// How [`DesktopElement::scroll_to`] animates the scroll.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScrollBehavior {
+    /// Jump to the new scroll position immediately.
+    Instant,
+    /// Animate to the new scroll position.
+    Smooth,
+}
+
+/// The position and size of an element's box, as reported by `Element.getBoundingClientRect()`.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+pub struct ElementRect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// A 2D measurement in CSS pixels, used for both scroll offset and scroll size.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+pub struct PixelsVector2D {
+    pub x: f64,
+    pub y: f64,
+}
+
+type QueryFuture<T> = Pin<Box<dyn Future<Output = Result<T, ElementQueryError>>>>;
+
+/// A handle to a mounted DOM element in the webview, handed to `onmounted` event listeners.
+///
+/// Every method here goes over the same webview `eval` bridge: it runs a small JS snippet against
+/// the mounted node and resolves once the webview posts the result back over IPC.
+#[derive(Clone)]
+pub struct DesktopElement {
+    id: ElementId,
+    query: QueryEngine,
+    eval: Rc<dyn Fn(&str)>,
+}
+
+impl DesktopElement {
+    pub(crate) fn new(id: ElementId, query: QueryEngine, eval: Rc<dyn Fn(&str)>) -> Self {
+        Self { id, query, eval }
+    }
+
+    fn node_ref(&self) -> String {
+        format!("window.interpreter.nodeForId({})", self.id.0)
+    }
+
+    fn eval_query<T: serde::de::DeserializeOwned + 'static>(&self, script: String) -> QueryFuture<T> {
+        let query = self.query.send(self.eval.as_ref(), &script);
+        Box::pin(async move { query.resolve::<T>().await })
+    }
+
+    /// Scrolls this element into view, animating the scroll according to `behavior`.
+    pub fn scroll_to(&self, behavior: ScrollBehavior) -> QueryFuture<()> {
+        let behavior = match behavior {
+            ScrollBehavior::Instant => "instant",
+            ScrollBehavior::Smooth => "smooth",
+        };
+        let script = format!(
+            "{}.scrollIntoView({{ behavior: \"{behavior}\" }}); return null;",
+            self.node_ref()
+        );
+        self.eval_query(script)
+    }
+
+    /// Reads this element's current position and size, via `getBoundingClientRect()`.
+    ///
+    /// Useful for measuring elements in a virtualized list, or deciding whether an element
+    /// already on-screen still needs to be scrolled into view.
+    pub fn get_client_rect(&self) -> QueryFuture<ElementRect> {
+        let script = format!(
+            "const r = {}.getBoundingClientRect(); \
+             return {{ x: r.x, y: r.y, width: r.width, height: r.height }};",
+            self.node_ref()
+        );
+        self.eval_query(script)
+    }
+
+    /// Reads this element's current scroll offset (`scrollLeft`/`scrollTop`).
+    pub fn get_scroll_offset(&self) -> QueryFuture<PixelsVector2D> {
+        let script = format!(
+            "const e = {}; return {{ x: e.scrollLeft, y: e.scrollTop }};",
+            self.node_ref()
+        );
+        self.eval_query(script)
+    }
+
+    /// Reads this element's total scrollable size (`scrollWidth`/`scrollHeight`).
+    pub fn get_scroll_size(&self) -> QueryFuture<PixelsVector2D> {
+        let script = format!(
+            "const e = {}; return {{ x: e.scrollWidth, y: e.scrollHeight }};",
+            self.node_ref()
+        );
+        self.eval_query(script)
+    }
+
+    /// Focuses this element if `focus` is true, or blurs it if `focus` is false.
+    pub fn set_focus(&self, focus: bool) -> QueryFuture<()> {
+        let method = if focus { "focus" } else { "blur" };
+        let script = format!("{}.{method}(); return null;", self.node_ref());
+        self.eval_query(script)
+    }
+}
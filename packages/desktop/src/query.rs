@@ -0,0 +1,92 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use futures_channel::oneshot;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// An id for a single in-flight `eval` round trip to the webview.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct QueryId(usize);
+
+/// Tracks `eval` scripts that are waiting on a result from the webview.
+///
+/// Every query is sent as a self-contained JS snippet that posts its result back over the
+/// existing IPC channel; [`QueryEngine::resolve`] is called from the desktop event loop's IPC
+/// handler once that message arrives, which wakes the matching [`Query`].
+#[derive(Clone, Default)]
+pub(crate) struct QueryEngine {
+    inner: Rc<RefCell<QueryEngineInner>>,
+}
+
+#[derive(Default)]
+struct QueryEngineInner {
+    next_id: usize,
+    pending: HashMap<QueryId, oneshot::Sender<Value>>,
+}
+
+impl QueryEngine {
+    /// Wraps `script` so its return value is posted back to us, then hands the wrapped script to
+    /// `eval` to run in the webview. `script` is the body of a function, so `return` works as
+    /// expected.
+    pub(crate) fn send(&self, eval: impl FnOnce(&str), script: &str) -> Query {
+        let (tx, rx) = oneshot::channel();
+        let id = {
+            let mut inner = self.inner.borrow_mut();
+            let id = QueryId(inner.next_id);
+            inner.next_id += 1;
+            inner.pending.insert(id, tx);
+            id
+        };
+
+        eval(&format!(
+            "(function(){{ \
+               const __result = (function(){{ {script} }})(); \
+               window.ipc.postMessage(JSON.stringify({{ method: \"query_result\", params: {{ id: {}, result: __result }} }})); \
+             }})();",
+            id.0
+        ));
+
+        Query { rx }
+    }
+
+    /// Delivers the result of a previously-sent query, waking its [`Query`] future.
+    pub(crate) fn resolve(&self, id: usize, value: Value) {
+        if let Some(tx) = self.inner.borrow_mut().pending.remove(&QueryId(id)) {
+            let _ = tx.send(value);
+        }
+    }
+}
+
+/// A single in-flight eval round trip. Resolves once the webview posts its result back.
+pub(crate) struct Query {
+    rx: oneshot::Receiver<Value>,
+}
+
+impl Query {
+    pub(crate) async fn resolve<T: DeserializeOwned>(self) -> Result<T, ElementQueryError> {
+        let value = self.rx.await.map_err(|_| ElementQueryError::Cancelled)?;
+        serde_json::from_value(value).map_err(ElementQueryError::Decode)
+    }
+}
+
+/// An error returned by a [`crate::element::DesktopElement`] geometry or focus query.
+#[derive(Debug)]
+pub enum ElementQueryError {
+    /// The webview was torn down before the query's result arrived.
+    Cancelled,
+    /// The webview's result didn't decode into the expected shape.
+    Decode(serde_json::Error),
+}
+
+impl std::fmt::Display for ElementQueryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cancelled => write!(f, "the webview was torn down before the query resolved"),
+            Self::Decode(err) => write!(f, "failed to decode the webview's response: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ElementQueryError {}
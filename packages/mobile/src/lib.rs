@@ -4,47 +4,81 @@
 
 pub use dioxus_desktop::*;
 use dioxus_lib::prelude::*;
+use std::any::Any;
 use std::sync::Mutex;
 
 pub mod launch_bindings {
-    use std::any::Any;
-
     use super::*;
     pub fn launch(
         root: fn() -> Element,
-        _contexts: Vec<Box<dyn Fn() -> Box<dyn Any> + Send + Sync>>,
-        _platform_config: Vec<Box<dyn Any>>,
+        contexts: Vec<Box<dyn Fn() -> Box<dyn Any> + Send + Sync>>,
+        platform_config: Vec<Box<dyn Any>>,
     ) {
-        super::launch(root);
+        super::launch(root, contexts, platform_config);
     }
 
-    pub fn launch_virtual_dom(_virtual_dom: VirtualDom, _desktop_config: Config) -> ! {
-        todo!()
+    pub fn launch_virtual_dom(virtual_dom: VirtualDom, desktop_config: Config) -> ! {
+        #[cfg(target_os = "android")]
+        {
+            let _ = (virtual_dom, desktop_config);
+            panic!(
+                "launch_virtual_dom is not supported on Android: a pre-built VirtualDom can't \
+                 cross the JNI `root()` trampoline, which only ever passes the root \
+                 `fn() -> Element` and contexts stashed in APP_FN_PTR across the activity-create call. Use \
+                 `launch` with a root function on Android instead."
+            );
+        }
+
+        #[cfg(not(target_os = "android"))]
+        {
+            dioxus_desktop::launch::launch_virtual_dom(virtual_dom, desktop_config)
+        }
     }
 }
 
 /// Launch via the binding API
-pub fn launch(incoming: fn() -> Element) {
+pub fn launch(
+    incoming: fn() -> Element,
+    contexts: Vec<Box<dyn Fn() -> Box<dyn Any> + Send + Sync>>,
+    platform_config: Vec<Box<dyn Any>>,
+) {
     #[cfg(target_os = "android")]
     {
-        *APP_FN_PTR.lock().unwrap() = Some(incoming);
+        // `platform_config` isn't threaded through here: it's a `Vec<Box<dyn Any>>` with no
+        // `Send` bound, and `root()` can run on a different thread than this call (the JNI
+        // `start_app`/`WryActivity` trampoline, not `JNI_OnLoad`), so stashing it in a static
+        // would be unsound. `contexts` is `Send + Sync` by its own bound, so that part is safe
+        // to carry across.
+        let _ = platform_config;
+        *APP_FN_PTR.lock().unwrap() = Some(AppBinding {
+            root: incoming,
+            contexts,
+        });
     }
 
     #[cfg(not(target_os = "android"))]
     {
-        dioxus_desktop::launch::launch(incoming, vec![], Default::default());
+        dioxus_desktop::launch::launch(incoming, contexts, platform_config);
     }
 }
 
-static APP_FN_PTR: Mutex<Option<fn() -> Element>> = Mutex::new(None);
+/// The pieces of [`launch`] that Android can't apply until the JNI trampoline calls back into
+/// [`root`], since the `WryActivity` only hands us a bare function pointer to call.
+struct AppBinding {
+    root: fn() -> Element,
+    contexts: Vec<Box<dyn Fn() -> Box<dyn Any> + Send + Sync>>,
+}
+
+static APP_FN_PTR: Mutex<Option<AppBinding>> = Mutex::new(None);
 
 pub fn root() {
-    let app = APP_FN_PTR
+    let AppBinding { root, contexts } = APP_FN_PTR
         .lock()
         .expect("APP_FN_PTR lock failed")
+        .take()
         .expect("Android to have set the app trampoline");
 
-    dioxus_desktop::launch::launch(app, vec![], Default::default());
+    dioxus_desktop::launch::launch(root, contexts, Default::default());
 }
 
 /// Expose the `Java_dev_dioxus_main_WryActivity_create` function to the JNI layer.
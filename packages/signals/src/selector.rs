@@ -2,7 +2,9 @@ use dioxus_core::prelude::*;
 
 use crate::dependency::Dependency;
 use crate::use_signal;
-use crate::{get_effect_stack, signal::SignalData, CopyValue, Effect, ReadOnlySignal, Signal};
+use crate::{
+    get_effect_stack, signal::SignalData, CopyValue, Effect, ReadOnlySignal, Signal, Storage,
+};
 
 /// Creates a new Selector. The selector will be run immediately and whenever any signal it reads changes.
 ///
@@ -23,7 +25,17 @@ use crate::{get_effect_stack, signal::SignalData, CopyValue, Effect, ReadOnlySig
 /// ```
 #[must_use = "Consider using `use_effect` to rerun a callback when dependencies change"]
 pub fn use_selector<R: PartialEq>(f: impl FnMut() -> R + 'static) -> ReadOnlySignal<R> {
-    once(|| selector(f))
+    use_maybe_sync_selector(f)
+}
+
+/// Creates a new Selector that may be `Send + Sync` depending on the storage type `S`. The selector will be run immediately and whenever any signal it reads changes.
+///
+/// Use [`use_maybe_sync_selector`] with [`crate::SyncStorage`] when you need to read the derived value from a background thread or a spawned task, for example inside `tokio::spawn`.
+#[must_use = "Consider using `use_effect` to rerun a callback when dependencies change"]
+pub fn use_maybe_sync_selector<R: PartialEq, S: Storage<SignalData<R>>>(
+    f: impl FnMut() -> R + 'static,
+) -> ReadOnlySignal<R, S> {
+    once(|| maybe_sync_selector(f))
 }
 
 /// Creates a new Selector with some local dependencies. The selector will be run immediately and whenever any signal it reads or any dependencies it tracks changes
@@ -45,14 +57,29 @@ pub fn use_selector<R: PartialEq>(f: impl FnMut() -> R + 'static) -> ReadOnlySig
 #[must_use = "Consider using `use_effect` to rerun a callback when dependencies change"]
 pub fn use_selector_with_dependencies<R: PartialEq, D: Dependency>(
     dependencies: D,
-    mut f: impl FnMut(D::Out) -> R + 'static,
+    f: impl FnMut(D::Out) -> R + 'static,
 ) -> ReadOnlySignal<R>
 where
     D::Out: 'static,
+{
+    use_maybe_sync_selector_with_dependencies(dependencies, f)
+}
+
+/// Creates a new Selector with some local dependencies that may be `Send + Sync` depending on the storage type `S`. The selector will be run immediately and whenever any signal it reads or any dependencies it tracks changes.
+///
+/// Use [`use_maybe_sync_selector_with_dependencies`] with [`crate::SyncStorage`] when you need to read the derived value from a background thread or a spawned task, for example inside `tokio::spawn`.
+#[must_use = "Consider using `use_effect` to rerun a callback when dependencies change"]
+pub fn use_maybe_sync_selector_with_dependencies<R: PartialEq, D: Dependency, S>(
+    dependencies: D,
+    mut f: impl FnMut(D::Out) -> R + 'static,
+) -> ReadOnlySignal<R, S>
+where
+    D::Out: 'static,
+    S: Storage<SignalData<R>>,
 {
     let dependencies_signal = use_signal(|| dependencies.out());
     let selector = once(|| {
-        selector(move || {
+        maybe_sync_selector(move || {
             let deref = &*dependencies_signal.read();
             f(deref.clone())
         })
@@ -67,8 +94,17 @@ where
 /// Creates a new Selector. The selector will be run immediately and whenever any signal it reads changes.
 ///
 /// Selectors can be used to efficiently compute derived data from signals.
-pub fn selector<R: PartialEq>(mut f: impl FnMut() -> R + 'static) -> ReadOnlySignal<R> {
-    let state = Signal::<R> {
+pub fn selector<R: PartialEq>(f: impl FnMut() -> R + 'static) -> ReadOnlySignal<R> {
+    maybe_sync_selector(f)
+}
+
+/// Creates a new Selector that may be `Send + Sync` depending on the storage type `S`. The selector will be run immediately and whenever any signal it reads changes.
+///
+/// The storage backend `S` controls where the boxed [`SignalData`] lives: [`crate::UnsyncStorage`] keeps it in a thread-local slab, while [`crate::SyncStorage`] keeps it behind a `RwLock`-guarded slab so the resulting [`ReadOnlySignal`] is `Send + Sync` and can be read from spawned threads or async tasks outside the current thread. The effect callback that recomputes the value is not stored in `S`: it is held in the [`Effect`]'s own storage whatever `S` is.
+pub fn maybe_sync_selector<R: PartialEq, S: Storage<SignalData<R>>>(
+    mut f: impl FnMut() -> R + 'static,
+) -> ReadOnlySignal<R, S> {
+    let state = Signal::<R, S> {
         inner: CopyValue::invalid(),
     };
     let effect = Effect {